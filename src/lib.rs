@@ -11,9 +11,10 @@ use std::{borrow::Borrow, collections::HashMap, hash::Hash, ops::Deref};
 *       - If same key is inserted multiple times, do we update the order of the Key ?
 *           - For simplicity sake, lets not update the order if the key is re-inserted.
 *   2. Delete Entry
-*       - once an entry is deleted from the map and if we don't delete the entry from the
-*         datastructure maintaing the order,  when we will iterate on the orderedMap,
-*         will need to check if the key exists in the under lying map or not.
+*       - `ordered_keys` must be kept in sync with `inner` on every delete, otherwise
+*         iteration has to pay for a `contains_key` check on every single step to skip
+*         over stale keys. `swap_remove`/`shift_remove` (and `remove`, which is just
+*         `shift_remove`) always remove from both.
 * */
 
 pub struct OrderedMap<K, V>
@@ -26,6 +27,9 @@ where
     // today in safe rust.
     inner: HashMap<K, V>,
     ordered_keys: Vec<K>,
+    // Maps a key to its position in `ordered_keys`, so `get_index_of` (and
+    // therefore `swap_remove`) don't have to scan the vector to find a key.
+    key_index: HashMap<K, usize>,
 }
 
 pub struct OrderedMapIter<'a, K, V>
@@ -43,17 +47,11 @@ where
     type Item = (&'a K, &'a V);
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.remainder_keys.is_empty() {
-            return None;
-        }
-
-        while let Some(key) = self.remainder_keys.first() {
-            self.remainder_keys = &self.remainder_keys[1..self.remainder_keys.len()];
-            if self.map.deref().contains_key(key) {
-                return Some((key, self.map.deref().get(key).unwrap()));
-            }
-        }
-        None
+        let (key, rest) = self.remainder_keys.split_first()?;
+        self.remainder_keys = rest;
+        // `ordered_keys` is kept in sync with `inner` on every mutation, so this
+        // lookup can never miss.
+        Some((key, self.map.deref().get(key).unwrap()))
     }
 }
 impl<K, V> Deref for OrderedMap<K, V>
@@ -67,6 +65,15 @@ where
     }
 }
 
+impl<K, V> Default for OrderedMap<K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<'a, K, V> OrderedMap<K, V>
 where
     K: Eq + Hash + Clone,
@@ -75,8 +82,44 @@ where
         OrderedMap {
             inner: HashMap::new(),
             ordered_keys: Vec::new(),
+            key_index: HashMap::new(),
         }
     }
+
+    /// Creates an empty map pre-sized to hold at least `capacity` entries
+    /// without reallocating.
+    pub fn with_capacity(capacity: usize) -> Self {
+        OrderedMap {
+            inner: HashMap::with_capacity(capacity),
+            ordered_keys: Vec::with_capacity(capacity),
+            key_index: HashMap::with_capacity(capacity),
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more entries.
+    pub fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional);
+        self.ordered_keys.reserve(additional);
+        self.key_index.reserve(additional);
+    }
+
+    /// Returns the number of entries in the map.
+    pub fn len(&self) -> usize {
+        self.ordered_keys.len()
+    }
+
+    /// Returns `true` if the map contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.ordered_keys.is_empty()
+    }
+
+    /// Removes all entries, keeping the allocated capacity.
+    pub fn clear(&mut self) {
+        self.inner.clear();
+        self.ordered_keys.clear();
+        self.key_index.clear();
+    }
+
     pub fn iter(&'a self) -> OrderedMapIter<'a, K, V> {
         OrderedMapIter {
             map: self,
@@ -86,9 +129,10 @@ where
 
     pub fn insert(&mut self, key: K, value: V) -> Option<V> {
         if !self.inner.contains_key(&key) {
-            self.ordered_keys.push(key.to_owned());
+            self.key_index.insert(key.clone(), self.ordered_keys.len());
+            self.ordered_keys.push(key.clone());
         }
-        return self.inner.insert(key.to_owned(), value);
+        self.inner.insert(key, value)
     }
 
     pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
@@ -96,10 +140,531 @@ where
         K: Borrow<Q>,
         Q: Eq + Hash + ?Sized,
     {
-        self.inner.remove(key)
+        self.shift_remove(key)
+    }
+
+    /// Returns the key-value pair at `index`, in insertion order.
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        let key = self.ordered_keys.get(index)?;
+        self.inner.get(key).map(|value| (key, value))
+    }
+
+    /// Returns the insertion-order position of `key`, if present. O(1): looks
+    /// up `key_index` rather than scanning `ordered_keys`.
+    pub fn get_index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.key_index.get(key).copied()
+    }
+
+    /// Removes `key`, filling the vacated slot in `ordered_keys` with the last
+    /// key instead of shifting everything after it. O(1): `key_index` gives
+    /// the slot directly, and only the moved key's entry needs updating. Does
+    /// not preserve the relative order of the remaining entries.
+    pub fn swap_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let index = self.key_index.remove(key)?;
+        let value = self.inner.remove(key)?;
+        self.ordered_keys.swap_remove(index);
+        if let Some(moved_key) = self.ordered_keys.get(index) {
+            self.key_index.insert(moved_key.clone(), index);
+        }
+        Some(value)
+    }
+
+    /// Removes `key`, shifting every key after it down by one. O(n), because
+    /// every key after `key`'s slot has its `key_index` entry shifted down;
+    /// the lookup of `key` itself is O(1). Preserves the relative order of
+    /// the remaining entries.
+    pub fn shift_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let index = self.key_index.remove(key)?;
+        let value = self.inner.remove(key)?;
+        self.ordered_keys.remove(index);
+        for shifted_key in &self.ordered_keys[index..] {
+            if let Some(position) = self.key_index.get_mut::<K>(shifted_key) {
+                *position -= 1;
+            }
+        }
+        Some(value)
+    }
+
+    /// Returns an [`Entry`] for in-place insert-or-update access to `key`, so
+    /// callers need not pair `contains_key` with `insert` themselves. The key
+    /// is still hashed more than once: here to pick the variant, and again
+    /// when the entry's value is read or inserted.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        if self.inner.contains_key(&key) {
+            Entry::Occupied(OccupiedEntry { map: self, key })
+        } else {
+            Entry::Vacant(VacantEntry { map: self, key })
+        }
+    }
+}
+
+pub enum Entry<'a, K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    Occupied(OccupiedEntry<'a, K, V>),
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+pub struct OccupiedEntry<'a, K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    map: &'a mut OrderedMap<K, V>,
+    key: K,
+}
+
+pub struct VacantEntry<'a, K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    map: &'a mut OrderedMap<K, V>,
+    key: K,
+}
+
+impl<'a, K, V> Entry<'a, K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    pub fn or_insert_with<F>(self, default: F) -> &'a mut V
+    where
+        F: FnOnce() -> V,
+    {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        match self {
+            Entry::Occupied(mut entry) => {
+                f(entry.get_mut());
+                Entry::Occupied(entry)
+            }
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+}
+
+impl<'a, K, V> OccupiedEntry<'a, K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    pub fn get_mut(&mut self) -> &mut V {
+        self.map
+            .inner
+            .get_mut(&self.key)
+            .expect("OccupiedEntry is only constructed for a key present in `inner`")
+    }
+
+    pub fn into_mut(self) -> &'a mut V {
+        self.map
+            .inner
+            .get_mut(&self.key)
+            .expect("OccupiedEntry is only constructed for a key present in `inner`")
+    }
+}
+
+impl<'a, K, V> VacantEntry<'a, K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    pub fn insert(self, value: V) -> &'a mut V {
+        // The vacant path is the only one that touches `ordered_keys`, and it
+        // pushes the key exactly once, matching the crate's rule that
+        // re-insertion never reorders an existing key.
+        self.map
+            .key_index
+            .insert(self.key.clone(), self.map.ordered_keys.len());
+        self.map.ordered_keys.push(self.key.clone());
+        self.map.inner.insert(self.key.clone(), value);
+        self.map
+            .inner
+            .get_mut(&self.key)
+            .expect("just inserted above")
+    }
+}
+
+/// An entry produced by [`OrderedMap::diff`], describing how a key's value
+/// changed between two maps.
+#[derive(Debug, PartialEq)]
+pub enum DiffItem<'a, K, V> {
+    Add(&'a K, &'a V),
+    Remove(&'a K, &'a V),
+    Update { key: &'a K, old: &'a V, new: &'a V },
+}
+
+enum DiffPhase {
+    Removes,
+    Adds,
+}
+
+pub struct OrderedDiffIter<'a, K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    this: &'a OrderedMap<K, V>,
+    other: &'a OrderedMap<K, V>,
+    self_keys: &'a [K],
+    other_keys: &'a [K],
+    phase: DiffPhase,
+}
+
+impl<'a, K, V> Iterator for OrderedDiffIter<'a, K, V>
+where
+    K: Eq + Hash + Clone,
+    V: PartialEq,
+{
+    type Item = DiffItem<'a, K, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.phase {
+                DiffPhase::Removes => {
+                    let (key, rest) = match self.self_keys.split_first() {
+                        Some(pair) => pair,
+                        None => {
+                            self.phase = DiffPhase::Adds;
+                            continue;
+                        }
+                    };
+                    self.self_keys = rest;
+                    let old = self.this.inner.get(key).expect("self_keys stays in sync with inner");
+                    match self.other.inner.get(key) {
+                        Some(new) if new != old => {
+                            return Some(DiffItem::Update { key, old, new })
+                        }
+                        Some(_) => continue,
+                        None => return Some(DiffItem::Remove(key, old)),
+                    }
+                }
+                DiffPhase::Adds => {
+                    let (key, rest) = self.other_keys.split_first()?;
+                    self.other_keys = rest;
+                    if self.this.inner.contains_key(key) {
+                        continue;
+                    }
+                    let new = self
+                        .other
+                        .inner
+                        .get(key)
+                        .expect("other_keys stays in sync with inner");
+                    return Some(DiffItem::Add(key, new));
+                }
+            }
+        }
+    }
+}
+
+impl<K, V> OrderedMap<K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    /// Computes what changed between `self` and `other`, in O(n+m): keys
+    /// present in both with differing values yield `Update`, keys only in
+    /// `self` yield `Remove`, and keys only in `other` yield `Add`.
+    pub fn diff<'a>(&'a self, other: &'a OrderedMap<K, V>) -> OrderedDiffIter<'a, K, V>
+    where
+        V: PartialEq,
+    {
+        OrderedDiffIter {
+            this: self,
+            other,
+            self_keys: &self.ordered_keys[..],
+            other_keys: &other.ordered_keys[..],
+            phase: DiffPhase::Removes,
+        }
+    }
+}
+
+pub struct OrderedMapIterMut<'a, K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    // `inner.iter_mut()` already hands out non-aliasing `&mut V`s for every
+    // entry in one safe call; we just place each one directly at its
+    // `key_index` slot instead of sorting, so building this is O(n).
+    pairs: std::vec::IntoIter<Option<(&'a K, &'a mut V)>>,
+}
+
+impl<'a, K, V> Iterator for OrderedMapIterMut<'a, K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pairs.by_ref().flatten().next()
+    }
+}
+
+pub struct OrderedMapIntoIter<K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    inner: HashMap<K, V>,
+    ordered_keys: std::vec::IntoIter<K>,
+}
+
+impl<K, V> Iterator for OrderedMapIntoIter<K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for key in self.ordered_keys.by_ref() {
+            if let Some(value) = self.inner.remove(&key) {
+                return Some((key, value));
+            }
+        }
+        None
+    }
+}
+
+impl<K, V> IntoIterator for OrderedMap<K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    type Item = (K, V);
+    type IntoIter = OrderedMapIntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        OrderedMapIntoIter {
+            inner: self.inner,
+            ordered_keys: self.ordered_keys.into_iter(),
+        }
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for OrderedMap<K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = OrderedMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K, V> Extend<(K, V)> for OrderedMap<K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K, V> OrderedMap<K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    /// Returns a mutable iterator in insertion order.
+    pub fn iter_mut(&mut self) -> OrderedMapIterMut<'_, K, V> {
+        let key_index = &self.key_index;
+        let mut pairs: Vec<Option<(&K, &mut V)>> = (0..self.ordered_keys.len()).map(|_| None).collect();
+        for (key, value) in self.inner.iter_mut() {
+            if let Some(&index) = key_index.get(key) {
+                pairs[index] = Some((key, value));
+            }
+        }
+        OrderedMapIterMut {
+            pairs: pairs.into_iter(),
+        }
+    }
+
+    /// Consumes the map, yielding its keys in insertion order.
+    pub fn into_keys(self) -> impl Iterator<Item = K> {
+        self.ordered_keys.into_iter()
+    }
+
+    /// Consumes the map, yielding its values in insertion order.
+    pub fn into_values(self) -> impl Iterator<Item = V> {
+        self.into_iter().map(|(_, value)| value)
+    }
+
+    /// Rebuilds `key_index` from the current `ordered_keys`. Used after any
+    /// operation that reorders the vector wholesale, where patching
+    /// individual entries would cost as much as just recomputing them all.
+    fn rebuild_key_index(&mut self) {
+        self.key_index.clear();
+        self.key_index.extend(
+            self.ordered_keys
+                .iter()
+                .enumerate()
+                .map(|(index, key)| (key.clone(), index)),
+        );
+    }
+
+    /// Reorders entries by key, ascending.
+    pub fn sort_keys(&mut self)
+    where
+        K: Ord,
+    {
+        self.ordered_keys.sort();
+        self.rebuild_key_index();
+    }
+
+    /// Reorders entries using `cmp`, which sees both the key and the value.
+    pub fn sort_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&K, &V, &K, &V) -> std::cmp::Ordering,
+    {
+        let inner = &self.inner;
+        self.ordered_keys.sort_by(|a, b| {
+            let a_value = inner.get(a).expect("ordered_keys is kept in sync with inner");
+            let b_value = inner.get(b).expect("ordered_keys is kept in sync with inner");
+            cmp(a, a_value, b, b_value)
+        });
+        self.rebuild_key_index();
+    }
+
+    /// Like [`OrderedMap::sort_by`], but not guaranteed to preserve the
+    /// relative order of equal entries.
+    pub fn sort_unstable_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&K, &V, &K, &V) -> std::cmp::Ordering,
+    {
+        let inner = &self.inner;
+        self.ordered_keys.sort_unstable_by(|a, b| {
+            let a_value = inner.get(a).expect("ordered_keys is kept in sync with inner");
+            let b_value = inner.get(b).expect("ordered_keys is kept in sync with inner");
+            cmp(a, a_value, b, b_value)
+        });
+        self.rebuild_key_index();
+    }
+
+    /// Reverses the insertion order in place.
+    pub fn reverse(&mut self) {
+        self.ordered_keys.reverse();
+        self.rebuild_key_index();
+    }
+
+    /// Moves the entry at position `from` to position `to`, shifting
+    /// everything in between. No-op if either index is out of bounds.
+    pub fn move_index(&mut self, from: usize, to: usize) {
+        if from == to || from >= self.ordered_keys.len() || to >= self.ordered_keys.len() {
+            return;
+        }
+        let key = self.ordered_keys.remove(from);
+        self.ordered_keys.insert(to, key);
+        self.rebuild_key_index();
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<K, V> serde::Serialize for OrderedMap<K, V>
+where
+    K: Eq + Hash + Clone + serde::Serialize,
+    V: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(self.ordered_keys.len()))?;
+        for (key, value) in self.iter() {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'de, K, V> serde::Deserialize<'de> for OrderedMap<K, V>
+where
+    K: Eq + Hash + Clone + serde::Deserialize<'de>,
+    V: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(OrderedMapVisitor {
+            marker: std::marker::PhantomData,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+struct OrderedMapVisitor<K, V> {
+    marker: std::marker::PhantomData<(K, V)>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K, V> serde::de::Visitor<'de> for OrderedMapVisitor<K, V>
+where
+    K: Eq + Hash + Clone + serde::Deserialize<'de>,
+    V: serde::Deserialize<'de>,
+{
+    type Value = OrderedMap<K, V>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a map")
+    }
+
+    fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        // Insert in arrival order so a JSON object's field order survives a
+        // decode/encode round-trip.
+        let mut map = OrderedMap::new();
+        while let Some((key, value)) = access.next_entry()? {
+            map.insert(key, value);
+        }
+        Ok(map)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip_preserves_insertion_order() {
+    let mut map = OrderedMap::new();
+    map.insert("name".to_string(), 4);
+    map.insert("kumarmo2".to_string(), 5);
+    map.insert("age".to_string(), 10);
+
+    let json = serde_json::to_string(&map).unwrap();
+    assert_eq!(json, r#"{"name":4,"kumarmo2":5,"age":10}"#);
+
+    let decoded: OrderedMap<String, i32> = serde_json::from_str(&json).unwrap();
+    let mut iterator = decoded.iter();
+    assert_eq!(iterator.next(), Some((&"name".to_string(), &4)));
+    assert_eq!(iterator.next(), Some((&"kumarmo2".to_string(), &5)));
+    assert_eq!(iterator.next(), Some((&"age".to_string(), &10)));
+    assert_eq!(iterator.next(), None);
+}
+
 #[test]
 fn it_works() {
     let map: OrderedMap<String, i32> = OrderedMap::new();
@@ -225,3 +790,263 @@ fn iteration_works_with_deletion() {
     let tuple = iterator.next().unwrap();
     assert_eq!((*tuple.0, *tuple.1), (4, 4));
 }
+
+#[test]
+fn get_index_returns_entry_in_insertion_order() {
+    let mut map = OrderedMap::new();
+    map.insert("name", 4);
+    map.insert("kumarmo2", 5);
+    assert_eq!(map.get_index(0), Some((&"name", &4)));
+    assert_eq!(map.get_index(1), Some((&"kumarmo2", &5)));
+    assert_eq!(map.get_index(2), None);
+}
+
+#[test]
+fn get_index_of_finds_position() {
+    let mut map = OrderedMap::new();
+    map.insert("name", 4);
+    map.insert("kumarmo2", 5);
+    assert_eq!(map.get_index_of("kumarmo2"), Some(1));
+    assert_eq!(map.get_index_of("missing"), None);
+}
+
+#[test]
+fn shift_remove_preserves_order_of_remaining_entries() {
+    let mut map = OrderedMap::new();
+    map.insert("name", 4);
+    map.insert("name2", 10);
+    map.insert("kumarmo2", 5);
+    assert_eq!(map.shift_remove("name2"), Some(10));
+    let mut iterator = map.iter();
+    assert_eq!((*iterator.next().unwrap().1), 4);
+    assert_eq!((*iterator.next().unwrap().1), 5);
+    assert_eq!(iterator.next(), None);
+}
+
+#[test]
+fn swap_remove_moves_last_key_into_vacated_slot() {
+    let mut map = OrderedMap::new();
+    map.insert("name", 4);
+    map.insert("name2", 10);
+    map.insert("kumarmo2", 5);
+    assert_eq!(map.swap_remove("name"), Some(4));
+    let mut iterator = map.iter();
+    assert_eq!((*iterator.next().unwrap().1), 5);
+    assert_eq!((*iterator.next().unwrap().1), 10);
+    assert_eq!(iterator.next(), None);
+}
+
+#[test]
+fn entry_or_insert_with_inserts_once_and_appends_to_order() {
+    let mut map: OrderedMap<&str, Vec<i32>> = OrderedMap::new();
+    map.entry("name").or_insert_with(Vec::new).push(1);
+    map.entry("name").or_insert_with(Vec::new).push(2);
+    map.entry("kumarmo2").or_insert_with(Vec::new).push(3);
+    let mut iterator = map.iter();
+    assert_eq!(iterator.next(), Some((&"name", &vec![1, 2])));
+    assert_eq!(iterator.next(), Some((&"kumarmo2", &vec![3])));
+    assert_eq!(iterator.next(), None);
+}
+
+#[test]
+fn entry_and_modify_only_runs_on_occupied_entry() {
+    let mut map = OrderedMap::new();
+    map.entry("name").and_modify(|v| *v += 1).or_insert(4);
+    map.entry("name").and_modify(|v| *v += 1).or_insert(4);
+    assert_eq!(map.get("name"), Some(&5));
+}
+
+#[test]
+fn diff_reports_add_remove_and_update() {
+    let mut left = OrderedMap::new();
+    left.insert("name", 4);
+    left.insert("kumarmo2", 5);
+    left.insert("stale", 1);
+
+    let mut right = OrderedMap::new();
+    right.insert("name", 4);
+    right.insert("kumarmo2", 10);
+    right.insert("new", 2);
+
+    let items: Vec<_> = left.diff(&right).collect();
+    assert_eq!(
+        items,
+        vec![
+            DiffItem::Update {
+                key: &"kumarmo2",
+                old: &5,
+                new: &10,
+            },
+            DiffItem::Remove(&"stale", &1),
+            DiffItem::Add(&"new", &2),
+        ]
+    );
+}
+
+#[test]
+fn diff_of_identical_maps_is_empty() {
+    let mut left = OrderedMap::new();
+    left.insert("name", 4);
+    let mut right = OrderedMap::new();
+    right.insert("name", 4);
+    assert_eq!(left.diff(&right).next(), None);
+}
+
+#[test]
+fn iter_mut_updates_every_value_in_insertion_order() {
+    let mut map = OrderedMap::new();
+    map.insert("name", 4);
+    map.insert("kumarmo2", 5);
+    for (_, value) in map.iter_mut() {
+        *value += 1;
+    }
+    let mut iterator = map.iter();
+    assert_eq!(iterator.next(), Some((&"name", &5)));
+    assert_eq!(iterator.next(), Some((&"kumarmo2", &6)));
+    assert_eq!(iterator.next(), None);
+}
+
+#[test]
+fn into_iter_yields_owned_pairs_in_insertion_order() {
+    let mut map = OrderedMap::new();
+    map.insert("name", 4);
+    map.insert("kumarmo2", 5);
+    let pairs: Vec<(&str, i32)> = map.into_iter().collect();
+    assert_eq!(pairs, vec![("name", 4), ("kumarmo2", 5)]);
+}
+
+#[test]
+fn into_keys_preserves_insertion_order() {
+    let mut map = OrderedMap::new();
+    map.insert("name", 4);
+    map.insert("kumarmo2", 5);
+    let keys: Vec<&str> = map.into_keys().collect();
+    assert_eq!(keys, vec!["name", "kumarmo2"]);
+}
+
+#[test]
+fn into_values_preserves_insertion_order() {
+    let mut map = OrderedMap::new();
+    map.insert("name", 4);
+    map.insert("kumarmo2", 5);
+    let values: Vec<i32> = map.into_values().collect();
+    assert_eq!(values, vec![4, 5]);
+}
+
+#[test]
+fn sort_keys_reorders_by_key_ascending() {
+    let mut map = OrderedMap::new();
+    map.insert("kumarmo2", 5);
+    map.insert("name", 4);
+    map.sort_keys();
+    let mut iterator = map.iter();
+    assert_eq!(iterator.next(), Some((&"kumarmo2", &5)));
+    assert_eq!(iterator.next(), Some((&"name", &4)));
+    assert_eq!(iterator.next(), None);
+}
+
+#[test]
+fn sort_by_reorders_using_values() {
+    let mut map = OrderedMap::new();
+    map.insert("name", 10);
+    map.insert("kumarmo2", 5);
+    map.sort_by(|_, a, _, b| a.cmp(b));
+    let mut iterator = map.iter();
+    assert_eq!(iterator.next(), Some((&"kumarmo2", &5)));
+    assert_eq!(iterator.next(), Some((&"name", &10)));
+    assert_eq!(iterator.next(), None);
+}
+
+#[test]
+fn reverse_flips_insertion_order() {
+    let mut map = OrderedMap::new();
+    map.insert("name", 4);
+    map.insert("kumarmo2", 5);
+    map.reverse();
+    let mut iterator = map.iter();
+    assert_eq!(iterator.next(), Some((&"kumarmo2", &5)));
+    assert_eq!(iterator.next(), Some((&"name", &4)));
+    assert_eq!(iterator.next(), None);
+}
+
+#[test]
+fn move_index_repositions_an_entry() {
+    let mut map = OrderedMap::new();
+    map.insert("name", 4);
+    map.insert("name2", 10);
+    map.insert("kumarmo2", 5);
+    map.move_index(0, 2);
+    let mut iterator = map.iter();
+    assert_eq!(iterator.next(), Some((&"name2", &10)));
+    assert_eq!(iterator.next(), Some((&"kumarmo2", &5)));
+    assert_eq!(iterator.next(), Some((&"name", &4)));
+    assert_eq!(iterator.next(), None);
+}
+
+#[test]
+fn with_capacity_starts_empty() {
+    let map: OrderedMap<String, i32> = OrderedMap::with_capacity(8);
+    assert_eq!(map.len(), 0);
+    assert!(map.is_empty());
+}
+
+#[test]
+fn len_is_empty_and_clear_track_entries() {
+    let mut map = OrderedMap::new();
+    assert!(map.is_empty());
+    map.insert("name", 4);
+    map.insert("kumarmo2", 5);
+    assert_eq!(map.len(), 2);
+    assert!(!map.is_empty());
+    map.clear();
+    assert_eq!(map.len(), 0);
+    assert!(map.is_empty());
+    assert_eq!(map.iter().next(), None);
+}
+
+#[test]
+fn from_iter_inserts_in_iterator_order() {
+    let map: OrderedMap<&str, i32> =
+        vec![("name", 4), ("kumarmo2", 5)].into_iter().collect();
+    let mut iterator = map.iter();
+    assert_eq!(iterator.next(), Some((&"name", &4)));
+    assert_eq!(iterator.next(), Some((&"kumarmo2", &5)));
+    assert_eq!(iterator.next(), None);
+}
+
+#[test]
+fn extend_appends_in_iterator_order() {
+    let mut map = OrderedMap::new();
+    map.insert("name", 4);
+    map.extend(vec![("kumarmo2", 5), ("name2", 10)]);
+    let mut iterator = map.iter();
+    assert_eq!(iterator.next(), Some((&"name", &4)));
+    assert_eq!(iterator.next(), Some((&"kumarmo2", &5)));
+    assert_eq!(iterator.next(), Some((&"name2", &10)));
+    assert_eq!(iterator.next(), None);
+}
+
+#[test]
+fn get_index_of_stays_correct_after_swap_remove() {
+    let mut map = OrderedMap::new();
+    map.insert("name", 4);
+    map.insert("name2", 10);
+    map.insert("kumarmo2", 5);
+    map.swap_remove("name");
+    // "kumarmo2" was the last key, and should now sit where "name" was.
+    assert_eq!(map.get_index_of("kumarmo2"), Some(0));
+    assert_eq!(map.get_index_of("name2"), Some(1));
+    assert_eq!(map.get_index_of("name"), None);
+}
+
+#[test]
+fn get_index_of_stays_correct_after_shift_remove() {
+    let mut map = OrderedMap::new();
+    map.insert("name", 4);
+    map.insert("name2", 10);
+    map.insert("kumarmo2", 5);
+    map.shift_remove("name");
+    assert_eq!(map.get_index_of("name2"), Some(0));
+    assert_eq!(map.get_index_of("kumarmo2"), Some(1));
+    assert_eq!(map.get_index_of("name"), None);
+}